@@ -5,12 +5,16 @@ mod create;
 mod update;
 mod list;
 mod merge;
+pub mod review;
 
 use snafu::ResultExt;
 
 use crate::{Octocrab, Page};
 
-pub use self::{create::CreatePullRequestBuilder, update::UpdatePullRequestBuilder, list::ListPullRequestsBuilder};
+pub use self::{
+    create::CreatePullRequestBuilder, update::UpdatePullRequestBuilder,
+    list::ListPullRequestsBuilder, review::{CreateReviewBuilder, ReviewAction},
+};
 
 /// A client to GitHub's pull request API.
 ///
@@ -291,6 +295,121 @@ impl<'octo> PullRequestHandler<'octo> {
         self.crab.post(url, Some(&map)).await
     }
 
+    /// Creates a new `CreateReviewBuilder` that can be configured to submit,
+    /// request changes on, comment on, or leave pending a review of the pull
+    /// request.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::pulls::ReviewAction;
+    ///
+    /// let review = octocrab.pulls("owner", "repo").create_review(101)
+    ///     .event(ReviewAction::RequestChanges)
+    ///     .body("Please add some tests.")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub fn create_review(&self, pr: u64) -> review::CreateReviewBuilder<'octo, '_> {
+        review::CreateReviewBuilder::new(self, pr)
+    }
+
+    /// Submits a pending review, transitioning it out of the `PENDING`
+    /// state with the given `event`.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// use octocrab::pulls::ReviewAction;
+    ///
+    /// octocrab::instance().pulls("owner", "repo")
+    ///     .submit_review(101, 1, ReviewAction::Approve, Some("LGTM!".to_string()))
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn submit_review(
+        &self,
+        pr: u64,
+        review_id: u64,
+        event: review::ReviewAction,
+        body: Option<String>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        let url = format!(
+            "repos/{owner}/{repo}/pulls/{pr}/reviews/{review_id}/events",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr,
+            review_id = review_id
+        );
+
+        let mut map = serde_json::Map::new();
+        map.insert("event".to_string(), event.to_string().into());
+        if let Some(body) = body {
+            map.insert("body".to_string(), body.into());
+        }
+
+        self.crab.post(url, Some(&map)).await
+    }
+
+    /// Dismisses a review, e.g. because it is stale or was submitted in
+    /// error. `message` explains why the review was dismissed.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().pulls("owner", "repo")
+    ///     .dismiss_review(101, 1, "Addressed in a follow-up commit.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn dismiss_review(
+        &self,
+        pr: u64,
+        review_id: u64,
+        message: impl Into<String>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        let url = format!(
+            "repos/{owner}/{repo}/pulls/{pr}/reviews/{review_id}/dismissals",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr,
+            review_id = review_id
+        );
+
+        let mut map = serde_json::Map::new();
+        map.insert("message".to_string(), message.into().into());
+
+        self.http_put(url, Some(&map)).await
+    }
+
+    /// Updates the body of an existing review.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// octocrab::instance().pulls("owner", "repo")
+    ///     .update_review(101, 1, "Looks good, updated after re-review.")
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn update_review(
+        &self,
+        pr: u64,
+        review_id: u64,
+        body: impl Into<String>,
+    ) -> crate::Result<crate::models::pulls::Review> {
+        let url = format!(
+            "repos/{owner}/{repo}/pulls/{pr}/reviews/{review_id}",
+            owner = self.owner,
+            repo = self.repo,
+            pr = pr,
+            review_id = review_id
+        );
+
+        let mut map = serde_json::Map::new();
+        map.insert("body".to_string(), body.into().into());
+
+        self.http_put(url, Some(&map)).await
+    }
+
     /// List all `FileDiff`s associated with the pull request.
     /// ```no_run
     /// # async fn run() -> octocrab::Result<()> {
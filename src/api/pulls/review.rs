@@ -0,0 +1,174 @@
+//! Submitting and managing reviews for a pull request.
+
+use serde::Serialize;
+
+use super::PullRequestHandler;
+
+/// The verdict a review is submitted with.
+///
+/// Corresponds to the GitHub `event` field on the
+/// [create a review](https://docs.github.com/en/rest/pulls/reviews#create-a-review-for-a-pull-request)
+/// endpoint. Leaving the builder's `event` unset posts a pending review that
+/// still needs to be submitted with [`PullRequestHandler::submit_review`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ReviewAction {
+    Approve,
+    RequestChanges,
+    Comment,
+}
+
+impl std::fmt::Display for ReviewAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            ReviewAction::Approve => "APPROVE",
+            ReviewAction::RequestChanges => "REQUEST_CHANGES",
+            ReviewAction::Comment => "COMMENT",
+        })
+    }
+}
+
+/// Which side of the diff a [`ReviewComment`] is anchored to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum Side {
+    #[serde(rename = "LEFT")]
+    Left,
+    #[serde(rename = "RIGHT")]
+    Right,
+}
+
+/// A single line-level draft comment included in a review.
+#[derive(Debug, Clone, Serialize)]
+pub struct ReviewComment {
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub position: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub line: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub side: Option<Side>,
+    pub body: String,
+}
+
+impl ReviewComment {
+    /// Creates a draft comment anchored to a line using the unified diff
+    /// `position`.
+    pub fn new(path: impl Into<String>, position: u64, body: impl Into<String>) -> Self {
+        Self {
+            path: path.into(),
+            position: Some(position),
+            line: None,
+            side: None,
+            body: body.into(),
+        }
+    }
+
+    /// Creates a draft comment anchored to a file `line` on a given `side`
+    /// of the diff, instead of a unified diff `position`.
+    pub fn on_line(
+        path: impl Into<String>,
+        line: u64,
+        side: Side,
+        body: impl Into<String>,
+    ) -> Self {
+        Self {
+            path: path.into(),
+            position: None,
+            line: Some(line),
+            side: Some(side),
+            body: body.into(),
+        }
+    }
+}
+
+/// A builder pattern struct for creating a new pull request review.
+///
+/// Created by [`PullRequestHandler::create_review`].
+#[derive(Serialize)]
+pub struct CreateReviewBuilder<'octo, 'b> {
+    #[serde(skip)]
+    handler: &'b PullRequestHandler<'octo>,
+    #[serde(skip)]
+    pr: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    event: Option<ReviewAction>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    commit_id: Option<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    comments: Vec<ReviewComment>,
+}
+
+impl<'octo, 'b> CreateReviewBuilder<'octo, 'b> {
+    pub(crate) fn new(handler: &'b PullRequestHandler<'octo>, pr: u64) -> Self {
+        Self {
+            handler,
+            pr,
+            body: None,
+            event: None,
+            commit_id: None,
+            comments: Vec::new(),
+        }
+    }
+
+    /// The body text of the review.
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    /// The verdict to submit the review with. Leaving this unset creates a
+    /// pending review that must later be submitted with
+    /// [`PullRequestHandler::submit_review`].
+    pub fn event(mut self, event: ReviewAction) -> Self {
+        self.event = Some(event);
+        self
+    }
+
+    /// The SHA of the commit that needs to be reviewed, rather than the most
+    /// recent commit on the pull request's branch.
+    pub fn commit_id(mut self, commit_id: impl Into<String>) -> Self {
+        self.commit_id = Some(commit_id.into());
+        self
+    }
+
+    /// Replaces the line-level draft comments included in the review.
+    pub fn comments(mut self, comments: impl Into<Vec<ReviewComment>>) -> Self {
+        self.comments = comments.into();
+        self
+    }
+
+    /// Adds a single line-level draft comment to the review.
+    pub fn comment(mut self, comment: ReviewComment) -> Self {
+        self.comments.push(comment);
+        self
+    }
+
+    /// Sends the request to create the review.
+    /// ```no_run
+    /// # async fn run() -> octocrab::Result<()> {
+    /// # let octocrab = octocrab::Octocrab::default();
+    /// use octocrab::pulls::ReviewAction;
+    ///
+    /// let review = octocrab
+    ///     .pulls("owner", "repo")
+    ///     .create_review(101)
+    ///     .event(ReviewAction::Approve)
+    ///     .body("Looks good!")
+    ///     .send()
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn send(self) -> crate::Result<crate::models::pulls::Review> {
+        let route = format!(
+            "repos/{owner}/{repo}/pulls/{pr}/reviews",
+            owner = self.handler.owner,
+            repo = self.handler.repo,
+            pr = self.pr,
+        );
+
+        self.handler.http_post(route, Some(&self)).await
+    }
+}